@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tauri::{AppHandle, Manager};
+
+use super::model::{Book, Chapter, DuplicateCluster};
+
+const BOOK_COLUMNS: &str = "id, path, title, author, language, tags, fingerprint";
+
+/// Shared handle to the embedded SQLite catalog, opened once in `setup` and
+/// accessed through Tauri managed state.
+pub struct BookDb(pub Mutex<Connection>);
+
+/// Schema for a brand-new database. `fingerprint` has no `NOT NULL`
+/// constraint here because `migrate_fingerprint_column` below has to add it
+/// to pre-existing installs via `ALTER TABLE`, which can't retroactively
+/// enforce non-null on rows it didn't create; the application (not the
+/// schema) is what guarantees every row it writes has one.
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS books (
+        id          INTEGER PRIMARY KEY AUTOINCREMENT,
+        path        TEXT NOT NULL UNIQUE,
+        title       TEXT NOT NULL,
+        author      TEXT,
+        language    TEXT,
+        tags        TEXT NOT NULL DEFAULT '[]',
+        fingerprint TEXT
+    );
+    CREATE TABLE IF NOT EXISTS chapters (
+        id       INTEGER PRIMARY KEY AUTOINCREMENT,
+        book_id  INTEGER NOT NULL REFERENCES books(id) ON DELETE CASCADE,
+        idx      INTEGER NOT NULL,
+        heading  TEXT NOT NULL,
+        content  TEXT NOT NULL
+    );
+";
+
+/// Open (creating if needed) the catalog database in the app's data
+/// directory, apply the schema, and migrate any pre-chunk0-3 install that's
+/// missing the `fingerprint` column.
+pub fn open(app_handle: &AppHandle) -> rusqlite::Result<Connection> {
+    let data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .expect("app data dir should be resolvable");
+    std::fs::create_dir_all(&data_dir).expect("app data dir should be creatable");
+
+    let conn = Connection::open(data_dir.join("catalog.sqlite"))?;
+    conn.execute_batch(SCHEMA)?;
+    migrate_fingerprint_column(&conn)?;
+    Ok(conn)
+}
+
+fn has_fingerprint_column(conn: &Connection) -> rusqlite::Result<bool> {
+    let mut stmt = conn.prepare("PRAGMA table_info(books)")?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(1)?;
+        if name == "fingerprint" {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Add the `fingerprint` column to a database created before chunk0-3 and
+/// backfill it by re-hashing each existing book's source file, since the
+/// column didn't exist when those rows were first ingested. Books whose
+/// source file has since moved or vanished, *or* that hash identically to
+/// a row already assigned that fingerprint (pre-existing duplicate
+/// imports, which this feature didn't exist to catch yet), get a
+/// fingerprint synthesized from their id instead, so the unique index
+/// below always has something non-null and non-colliding to index.
+fn migrate_fingerprint_column(conn: &Connection) -> rusqlite::Result<()> {
+    if !has_fingerprint_column(conn)? {
+        conn.execute("ALTER TABLE books ADD COLUMN fingerprint TEXT", [])?;
+    }
+
+    let mut seen: std::collections::HashSet<String> = conn
+        .prepare("SELECT fingerprint FROM books WHERE fingerprint IS NOT NULL")?
+        .query_map([], |row| row.get(0))?
+        .collect::<rusqlite::Result<_>>()?;
+
+    let mut stmt = conn.prepare("SELECT id, path FROM books WHERE fingerprint IS NULL")?;
+    let unfingerprinted: Vec<(i64, String)> = stmt
+        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+        .collect::<rusqlite::Result<_>>()?;
+    drop(stmt);
+
+    for (id, path) in unfingerprinted {
+        let hashed = super::fingerprint::fingerprint_file(Path::new(&path)).ok();
+        let fingerprint = match hashed {
+            Some(hashed) if !seen.contains(&hashed) => hashed,
+            _ => format!("unmigrated-book-{id}"),
+        };
+        seen.insert(fingerprint.clone());
+        conn.execute(
+            "UPDATE books SET fingerprint = ?1 WHERE id = ?2",
+            params![fingerprint, id],
+        )?;
+    }
+
+    conn.execute_batch("CREATE UNIQUE INDEX IF NOT EXISTS books_fingerprint_idx ON books(fingerprint)")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrates_legacy_db_with_duplicate_content_without_crashing() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE books (
+                id       INTEGER PRIMARY KEY AUTOINCREMENT,
+                path     TEXT NOT NULL UNIQUE,
+                title    TEXT NOT NULL,
+                author   TEXT,
+                language TEXT,
+                tags     TEXT NOT NULL DEFAULT '[]'
+            );",
+        )
+        .unwrap();
+
+        let dir = std::env::temp_dir().join(format!(
+            "read-bridge-migration-test-{}",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path_a = dir.join("a.md");
+        let path_b = dir.join("b.md");
+        std::fs::write(&path_a, b"# Same Book\n\nidentical content").unwrap();
+        std::fs::write(&path_b, b"# Same Book\n\nidentical content").unwrap();
+
+        conn.execute(
+            "INSERT INTO books (path, title) VALUES (?1, 'Same Book')",
+            params![path_a.to_string_lossy()],
+        )
+        .unwrap();
+        conn.execute(
+            "INSERT INTO books (path, title) VALUES (?1, 'Same Book')",
+            params![path_b.to_string_lossy()],
+        )
+        .unwrap();
+
+        migrate_fingerprint_column(&conn).expect("migration must not fail on pre-existing duplicates");
+
+        let fingerprints: Vec<String> = conn
+            .prepare("SELECT fingerprint FROM books ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_ne!(fingerprints[0], fingerprints[1]);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
+
+pub fn find_by_path(conn: &Connection, path: &str) -> rusqlite::Result<Option<Book>> {
+    conn.query_row(
+        &format!("SELECT {BOOK_COLUMNS} FROM books WHERE path = ?1"),
+        params![path],
+        row_to_book,
+    )
+    .optional()
+}
+
+pub fn find_by_id(conn: &Connection, id: i64) -> rusqlite::Result<Option<Book>> {
+    conn.query_row(
+        &format!("SELECT {BOOK_COLUMNS} FROM books WHERE id = ?1"),
+        params![id],
+        row_to_book,
+    )
+    .optional()
+}
+
+pub fn find_by_fingerprint(conn: &Connection, fingerprint: &str) -> rusqlite::Result<Option<Book>> {
+    conn.query_row(
+        &format!("SELECT {BOOK_COLUMNS} FROM books WHERE fingerprint = ?1"),
+        params![fingerprint],
+        row_to_book,
+    )
+    .optional()
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn insert_book(
+    conn: &Connection,
+    path: &str,
+    title: &str,
+    author: Option<&str>,
+    language: Option<&str>,
+    tags: &[String],
+    fingerprint: &str,
+) -> rusqlite::Result<Book> {
+    let tags_json = serde_json::to_string(tags).unwrap_or_else(|_| "[]".to_string());
+    conn.execute(
+        "INSERT INTO books (path, title, author, language, tags, fingerprint) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![path, title, author, language, tags_json, fingerprint],
+    )?;
+    let id = conn.last_insert_rowid();
+    Ok(Book {
+        id,
+        path: path.to_string(),
+        title: title.to_string(),
+        author: author.map(str::to_string),
+        language: language.map(str::to_string),
+        tags: tags.to_vec(),
+        fingerprint: fingerprint.to_string(),
+    })
+}
+
+pub fn insert_chapters(
+    conn: &Connection,
+    book_id: i64,
+    chapters: &[super::format::ParsedChapter],
+) -> rusqlite::Result<()> {
+    for (idx, chapter) in chapters.iter().enumerate() {
+        conn.execute(
+            "INSERT INTO chapters (book_id, idx, heading, content) VALUES (?1, ?2, ?3, ?4)",
+            params![book_id, idx as i64, chapter.heading, chapter.content],
+        )?;
+    }
+    Ok(())
+}
+
+pub fn list_books(conn: &Connection) -> rusqlite::Result<Vec<Book>> {
+    let mut stmt = conn.prepare(&format!("SELECT {BOOK_COLUMNS} FROM books ORDER BY id"))?;
+    let rows = stmt.query_map([], row_to_book)?;
+    rows.collect()
+}
+
+/// Group every book in the catalog by fingerprint, keeping only groups with
+/// more than one member.
+pub fn find_duplicate_books(conn: &Connection) -> rusqlite::Result<Vec<DuplicateCluster>> {
+    let books = list_books(conn)?;
+    let mut by_fingerprint: HashMap<String, Vec<Book>> = HashMap::new();
+    for book in books {
+        by_fingerprint
+            .entry(book.fingerprint.clone())
+            .or_default()
+            .push(book);
+    }
+
+    let mut clusters: Vec<DuplicateCluster> = by_fingerprint
+        .into_iter()
+        .filter(|(_, books)| books.len() > 1)
+        .map(|(fingerprint, books)| DuplicateCluster { fingerprint, books })
+        .collect();
+    clusters.sort_by(|a, b| a.fingerprint.cmp(&b.fingerprint));
+    Ok(clusters)
+}
+
+pub fn get_chapter(conn: &Connection, book_id: i64, index: i64) -> rusqlite::Result<Option<Chapter>> {
+    conn.query_row(
+        "SELECT id, book_id, idx, heading, content FROM chapters WHERE book_id = ?1 AND idx = ?2",
+        params![book_id, index],
+        |row| {
+            Ok(Chapter {
+                id: row.get(0)?,
+                book_id: row.get(1)?,
+                idx: row.get(2)?,
+                heading: row.get(3)?,
+                content: row.get(4)?,
+            })
+        },
+    )
+    .optional()
+}
+
+fn row_to_book(row: &rusqlite::Row) -> rusqlite::Result<Book> {
+    let tags_json: String = row.get(5)?;
+    let tags = serde_json::from_str(&tags_json).unwrap_or_default();
+    Ok(Book {
+        id: row.get(0)?,
+        path: row.get(1)?,
+        title: row.get(2)?,
+        author: row.get(3)?,
+        language: row.get(4)?,
+        tags,
+        fingerprint: row.get(6)?,
+    })
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use zip::ZipArchive;
+
+use super::format::{ParsedBook, ParsedChapter, Parser};
+
+fn read_zip_entry(zip: &mut ZipArchive<File>, name: &str) -> Result<String, String> {
+    let mut entry = zip
+        .by_name(name)
+        .map_err(|e| format!("missing {} in epub: {}", name, e))?;
+    let mut contents = String::new();
+    entry
+        .read_to_string(&mut contents)
+        .map_err(|e| format!("{} is not valid UTF-8: {}", name, e))?;
+    Ok(contents)
+}
+
+/// Read `META-INF/container.xml` to find the path of the package (OPF)
+/// document, per the EPUB Open Container Format spec.
+fn find_opf_path(zip: &mut ZipArchive<File>) -> Result<String, String> {
+    let container = read_zip_entry(zip, "META-INF/container.xml")?;
+    let doc = roxmltree::Document::parse(&container).map_err(|e| e.to_string())?;
+
+    doc.descendants()
+        .find(|n| n.has_tag_name("rootfile"))
+        .and_then(|n| n.attribute("full-path"))
+        .map(str::to_string)
+        .ok_or_else(|| "container.xml has no rootfile entry".to_string())
+}
+
+fn join_zip_path(base_dir: &str, href: &str) -> String {
+    if base_dir.is_empty() {
+        href.to_string()
+    } else {
+        format!("{}/{}", base_dir, href)
+    }
+}
+
+/// Decode percent-escapes in a manifest href (e.g. `%20` for a space) and
+/// drop any `#fragment` suffix, since real-world EPUBs use both and the zip
+/// archive only ever has entries under the raw, unescaped path.
+fn decode_href(href: &str) -> String {
+    let without_fragment = href.split('#').next().unwrap_or(href);
+
+    let bytes = without_fragment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+            if let Some(byte) = hex.and_then(|hex| u8::from_str_radix(hex, 16).ok()) {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// Strip XHTML markup down to plain text, collapsing whitespace the way a
+/// reader would expect from rendered prose.
+fn strip_tags(xhtml: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in xhtml.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    const CONTAINER_XML: &str = r#"<?xml version="1.0"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="OEBPS/content.opf" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>"#;
+
+    /// `chap2.xhtml`'s href is percent-encoded with a literal space, which
+    /// must be decoded before the zip lookup below, and `chap3.xhtml` is
+    /// deliberately missing from the archive to exercise the skipped-chapter
+    /// warning path.
+    const CONTENT_OPF: &str = r#"<?xml version="1.0"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="2.0">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+    <dc:title>Sample Book</dc:title>
+    <dc:creator>Jane Author</dc:creator>
+    <dc:language>en</dc:language>
+  </metadata>
+  <manifest>
+    <item id="chap1" href="chap1.xhtml" media-type="application/xhtml+xml"/>
+    <item id="chap2" href="chap%202.xhtml" media-type="application/xhtml+xml"/>
+    <item id="chap3" href="chap3.xhtml" media-type="application/xhtml+xml"/>
+  </manifest>
+  <spine>
+    <itemref idref="chap1"/>
+    <itemref idref="chap2"/>
+    <itemref idref="chap3"/>
+  </spine>
+</package>"#;
+
+    fn build_epub_fixture(path: &Path) {
+        let file = File::create(path).unwrap();
+        let mut zip = ZipWriter::new(file);
+        let options = FileOptions::default();
+
+        zip.start_file("META-INF/container.xml", options).unwrap();
+        zip.write_all(CONTAINER_XML.as_bytes()).unwrap();
+
+        zip.start_file("OEBPS/content.opf", options).unwrap();
+        zip.write_all(CONTENT_OPF.as_bytes()).unwrap();
+
+        zip.start_file("OEBPS/chap1.xhtml", options).unwrap();
+        zip.write_all(b"<html><body><h1>Chapter One</h1><p>Hello world.</p></body></html>")
+            .unwrap();
+
+        // Stored unescaped, as zip entries are; the parser must decode the
+        // manifest's `chap%202.xhtml` href to find it.
+        zip.start_file("OEBPS/chap 2.xhtml", options).unwrap();
+        zip.write_all(
+            b"<html><body><h1>Chapter Two</h1><p>Second chapter text.</p></body></html>",
+        )
+        .unwrap();
+
+        // chap3.xhtml is intentionally never written.
+
+        zip.finish().unwrap();
+    }
+
+    fn temp_epub_path(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "read-bridge-epub-test-{}-{}.epub",
+            std::process::id(),
+            name
+        ));
+        build_epub_fixture(&path);
+        path
+    }
+
+    #[test]
+    fn parses_metadata_chapters_and_percent_encoded_hrefs() {
+        let path = temp_epub_path("fixture");
+
+        let parsed = EpubParser.parse(&path).unwrap();
+
+        assert_eq!(parsed.title, "Sample Book");
+        assert_eq!(parsed.author.as_deref(), Some("Jane Author"));
+        assert_eq!(parsed.language.as_deref(), Some("en"));
+        assert_eq!(parsed.chapters.len(), 2);
+        assert_eq!(parsed.chapters[0].heading, "Chapter One");
+        assert!(parsed.chapters[0].content.contains("Hello world."));
+        assert_eq!(parsed.chapters[1].heading, "Chapter Two");
+        assert!(parsed.chapters[1].content.contains("Second chapter text."));
+
+        assert_eq!(parsed.warnings.len(), 1);
+        assert!(parsed.warnings[0].contains("skipped 1 of 3"));
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn decode_href_strips_percent_escapes_and_fragments() {
+        assert_eq!(decode_href("chap%202.xhtml"), "chap 2.xhtml");
+        assert_eq!(decode_href("chap1.xhtml#section2"), "chap1.xhtml");
+        assert_eq!(decode_href("plain.xhtml"), "plain.xhtml");
+    }
+}
+
+/// Parses EPUB archives by following the OCF container to the OPF package
+/// document, then the spine/manifest to order chapter documents, pulling
+/// title/author from the package's Dublin Core metadata.
+pub struct EpubParser;
+
+impl Parser for EpubParser {
+    fn parse(&self, path: &Path) -> Result<ParsedBook, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        let mut zip = ZipArchive::new(file).map_err(|e| e.to_string())?;
+
+        let opf_path = find_opf_path(&mut zip)?;
+        let opf_dir = Path::new(&opf_path)
+            .parent()
+            .map(|p| p.to_string_lossy().replace('\\', "/"))
+            .unwrap_or_default();
+
+        let opf = read_zip_entry(&mut zip, &opf_path)?;
+        let doc = roxmltree::Document::parse(&opf).map_err(|e| e.to_string())?;
+
+        let title = doc
+            .descendants()
+            .find(|n| n.tag_name().name() == "title")
+            .and_then(|n| n.text())
+            .map(str::to_string)
+            .unwrap_or_else(|| {
+                path.file_stem()
+                    .map(|s| s.to_string_lossy().to_string())
+                    .unwrap_or_else(|| path.to_string_lossy().to_string())
+            });
+
+        let author = doc
+            .descendants()
+            .find(|n| n.tag_name().name() == "creator")
+            .and_then(|n| n.text())
+            .map(str::to_string);
+
+        let language = doc
+            .descendants()
+            .find(|n| n.tag_name().name() == "language")
+            .and_then(|n| n.text())
+            .map(str::to_string);
+
+        let manifest: HashMap<&str, &str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("item"))
+            .filter_map(|n| Some((n.attribute("id")?, n.attribute("href")?)))
+            .collect();
+
+        let spine_hrefs: Vec<&str> = doc
+            .descendants()
+            .filter(|n| n.has_tag_name("itemref"))
+            .filter_map(|n| n.attribute("idref"))
+            .filter_map(|idref| manifest.get(idref).copied())
+            .collect();
+
+        let mut chapters = Vec::new();
+        let mut skipped = 0usize;
+        for href in spine_hrefs {
+            let zip_path = join_zip_path(&opf_dir, &decode_href(href));
+            let xhtml = match read_zip_entry(&mut zip, &zip_path) {
+                Ok(xhtml) => xhtml,
+                Err(_) => {
+                    skipped += 1;
+                    continue;
+                }
+            };
+
+            let heading = roxmltree::Document::parse(&xhtml)
+                .ok()
+                .and_then(|doc| {
+                    doc.descendants()
+                        .find(|n| matches!(n.tag_name().name(), "h1" | "h2" | "title"))
+                        .and_then(|n| n.text().map(str::to_string))
+                })
+                .unwrap_or_else(|| format!("Chapter {}", chapters.len() + 1));
+
+            chapters.push(ParsedChapter {
+                heading,
+                content: strip_tags(&xhtml),
+            });
+        }
+
+        let mut warnings = Vec::new();
+        if skipped > 0 {
+            warnings.push(format!(
+                "skipped {} of {} spine chapters in {}: href not found in archive",
+                skipped,
+                chapters.len() + skipped,
+                path.display()
+            ));
+        }
+
+        Ok(ParsedBook {
+            title,
+            author,
+            language,
+            tags: Vec::new(),
+            chapters,
+            warnings,
+        })
+    }
+}
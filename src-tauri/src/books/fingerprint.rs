@@ -0,0 +1,114 @@
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// Files at or under this size are hashed in full; larger files are hashed
+/// by sampling, since users mostly dedup large ebooks where reading the
+/// whole thing on every scan would be wasteful.
+const FULL_HASH_THRESHOLD: u64 = 16 * 1024;
+
+/// Size of each sampled window read from a large file.
+const WINDOW_SIZE: usize = 4 * 1024;
+
+/// Compute a cheap content fingerprint for `path`, suitable for detecting
+/// duplicate imports of the same book. Files at or under `FULL_HASH_THRESHOLD`
+/// are hashed in full; larger files are fingerprinted from three fixed windows
+/// (start, middle, end) plus the total length, which is enough to catch the
+/// common case of an identical file copied or synced to a new location
+/// without reading it end to end.
+pub fn fingerprint_file(path: &Path) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+
+    let mut hasher = blake3::Hasher::new();
+
+    if len <= FULL_HASH_THRESHOLD {
+        let mut buf = Vec::with_capacity(len as usize);
+        file.read_to_end(&mut buf)?;
+        hasher.update(&buf);
+    } else {
+        hasher.update(&read_window(&mut file, 0, WINDOW_SIZE)?);
+        hasher.update(&read_window(&mut file, len / 2, WINDOW_SIZE)?);
+        let tail_start = len.saturating_sub(WINDOW_SIZE as u64);
+        hasher.update(&read_window(&mut file, tail_start, WINDOW_SIZE)?);
+        hasher.update(&len.to_le_bytes());
+    }
+
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+fn read_window(file: &mut File, offset: u64, size: usize) -> std::io::Result<Vec<u8>> {
+    file.seek(SeekFrom::Start(offset))?;
+    let mut buf = vec![0u8; size];
+    let read = file.read(&mut buf)?;
+    buf.truncate(read);
+    Ok(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_file(name: &str, contents: &[u8]) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "read-bridge-fingerprint-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn identical_small_files_under_the_threshold_hash_equal() {
+        let a = write_temp_file("small-a", b"the same short content");
+        let b = write_temp_file("small-b", b"the same short content");
+
+        assert_eq!(
+            fingerprint_file(&a).unwrap(),
+            fingerprint_file(&b).unwrap()
+        );
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+    }
+
+    #[test]
+    fn files_right_at_the_full_hash_threshold_boundary_differ_by_one_byte() {
+        let at_threshold = vec![b'a'; FULL_HASH_THRESHOLD as usize];
+        let mut one_over = at_threshold.clone();
+        one_over.push(b'b');
+
+        let a = write_temp_file("at-threshold", &at_threshold);
+        let b = write_temp_file("one-over", &one_over);
+
+        // The boundary-sized file takes the full-hash path, the one-byte-larger
+        // file takes the sampled-window path; they must not collide.
+        assert_ne!(
+            fingerprint_file(&a).unwrap(),
+            fingerprint_file(&b).unwrap()
+        );
+
+        let _ = std::fs::remove_file(&a);
+        let _ = std::fs::remove_file(&b);
+    }
+
+    #[test]
+    fn large_files_differing_only_in_the_middle_window_are_distinguished() {
+        let len = (FULL_HASH_THRESHOLD as usize) * 2;
+        let a = vec![b'x'; len];
+        let mut b = a.clone();
+        b[len / 2] = b'y';
+
+        let path_a = write_temp_file("large-a", &a);
+        let path_b = write_temp_file("large-b", &b);
+
+        assert_ne!(
+            fingerprint_file(&path_a).unwrap(),
+            fingerprint_file(&path_b).unwrap()
+        );
+
+        let _ = std::fs::remove_file(&path_a);
+        let _ = std::fs::remove_file(&path_b);
+    }
+}
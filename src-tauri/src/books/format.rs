@@ -0,0 +1,57 @@
+use std::path::Path;
+
+/// The source formats the catalog knows how to ingest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Markdown,
+    Epub,
+    PlainText,
+}
+
+impl Format {
+    /// Determine the format from a file's extension, or `None` if it isn't
+    /// one the catalog ingests.
+    pub fn from_path(path: &Path) -> Option<Format> {
+        match path.extension().and_then(|ext| ext.to_str())?.to_lowercase().as_str() {
+            "md" | "markdown" => Some(Format::Markdown),
+            "epub" => Some(Format::Epub),
+            "txt" => Some(Format::PlainText),
+            _ => None,
+        }
+    }
+}
+
+/// A chapter extracted from a source document, before it is assigned a
+/// database id.
+pub struct ParsedChapter {
+    pub heading: String,
+    pub content: String,
+}
+
+/// The common representation every `Parser` normalizes a source file into,
+/// regardless of its on-disk format.
+pub struct ParsedBook {
+    pub title: String,
+    pub author: Option<String>,
+    pub language: Option<String>,
+    pub tags: Vec<String>,
+    pub chapters: Vec<ParsedChapter>,
+    /// Non-fatal issues encountered while parsing (e.g. a chapter that
+    /// couldn't be located in an EPUB archive), surfaced as log warnings by
+    /// the caller rather than failing the whole ingest.
+    pub warnings: Vec<String>,
+}
+
+/// Normalizes one source format into a `ParsedBook`.
+pub trait Parser {
+    fn parse(&self, path: &Path) -> Result<ParsedBook, String>;
+}
+
+/// Look up the `Parser` for a given format.
+pub fn parser_for(format: Format) -> Box<dyn Parser> {
+    match format {
+        Format::Markdown => Box::new(super::markdown::MarkdownParser),
+        Format::Epub => Box::new(super::epub::EpubParser),
+        Format::PlainText => Box::new(super::plaintext::PlainTextParser),
+    }
+}
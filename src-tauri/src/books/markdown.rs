@@ -0,0 +1,150 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+use super::format::{ParsedBook, ParsedChapter, Parser};
+
+/// Frontmatter fields we recognize; anything else in the block is ignored.
+#[derive(Debug, Default, Deserialize)]
+struct Frontmatter {
+    title: Option<String>,
+    author: Option<String>,
+    language: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Split a markdown source into its frontmatter (YAML `---` or TOML `+++`
+/// fenced block, if present) and the remaining body.
+fn split_frontmatter(source: &str) -> (Frontmatter, &str) {
+    if let Some(rest) = source.strip_prefix("---\n") {
+        if let Some(end) = rest.find("\n---") {
+            let yaml = &rest[..end];
+            let body = rest[end + 4..].trim_start_matches('\n');
+            let frontmatter = serde_yaml::from_str(yaml).unwrap_or_default();
+            return (frontmatter, body);
+        }
+    }
+
+    if let Some(rest) = source.strip_prefix("+++\n") {
+        if let Some(end) = rest.find("\n+++") {
+            let toml = &rest[..end];
+            let body = rest[end + 4..].trim_start_matches('\n');
+            let frontmatter = toml::from_str(toml).unwrap_or_default();
+            return (frontmatter, body);
+        }
+    }
+
+    (Frontmatter::default(), source)
+}
+
+/// Split a book's body into chapters on top-level (`#`) and second-level
+/// (`##`) headings. Content preceding the first heading, if any, becomes an
+/// untitled leading chapter.
+fn split_chapters(body: &str) -> Vec<ParsedChapter> {
+    let mut chapters = Vec::new();
+    let mut current_heading: Option<String> = None;
+    let mut current_content = String::new();
+
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        let is_heading = trimmed.starts_with("# ") || trimmed.starts_with("## ");
+
+        if is_heading {
+            if current_heading.is_some() || !current_content.trim().is_empty() {
+                chapters.push(ParsedChapter {
+                    heading: current_heading.take().unwrap_or_default(),
+                    content: current_content.trim().to_string(),
+                });
+                current_content.clear();
+            }
+            current_heading = Some(trimmed.trim_start_matches('#').trim().to_string());
+        } else {
+            current_content.push_str(line);
+            current_content.push('\n');
+        }
+    }
+
+    if current_heading.is_some() || !current_content.trim().is_empty() {
+        chapters.push(ParsedChapter {
+            heading: current_heading.unwrap_or_default(),
+            content: current_content.trim().to_string(),
+        });
+    }
+
+    chapters
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leading_content_before_first_heading_becomes_untitled_chapter() {
+        let chapters = split_chapters("some preamble\n\n# Chapter One\nbody text\n");
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].heading, "");
+        assert_eq!(chapters[0].content, "some preamble");
+        assert_eq!(chapters[1].heading, "Chapter One");
+        assert_eq!(chapters[1].content, "body text");
+    }
+
+    #[test]
+    fn second_level_headings_also_split_chapters() {
+        let chapters = split_chapters("# Part One\nintro\n## Section A\nsection body\n");
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].heading, "Part One");
+        assert_eq!(chapters[1].heading, "Section A");
+        assert_eq!(chapters[1].content, "section body");
+    }
+
+    #[test]
+    fn empty_body_produces_no_chapters() {
+        assert!(split_chapters("").is_empty());
+        assert!(split_chapters("   \n\n  ").is_empty());
+    }
+
+    #[test]
+    fn consecutive_headings_with_no_body_keep_empty_content() {
+        let chapters = split_chapters("# One\n# Two\nbody\n");
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].heading, "One");
+        assert_eq!(chapters[0].content, "");
+        assert_eq!(chapters[1].heading, "Two");
+        assert_eq!(chapters[1].content, "body");
+    }
+
+    #[test]
+    fn a_third_level_heading_is_not_treated_as_a_chapter_break() {
+        let chapters = split_chapters("# One\nintro\n### not a split\nmore\n");
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(chapters[0].heading, "One");
+        assert_eq!(chapters[0].content, "intro\n### not a split\nmore");
+    }
+}
+
+/// Parses markdown files with optional YAML/TOML frontmatter, splitting the
+/// body into chapters on `#` and `##` headings.
+pub struct MarkdownParser;
+
+impl Parser for MarkdownParser {
+    fn parse(&self, path: &Path) -> Result<ParsedBook, String> {
+        let source = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let (frontmatter, body) = split_frontmatter(&source);
+
+        let title = frontmatter.title.unwrap_or_else(|| {
+            path.file_stem()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_else(|| path.to_string_lossy().to_string())
+        });
+
+        Ok(ParsedBook {
+            title,
+            author: frontmatter.author,
+            language: frontmatter.language,
+            tags: frontmatter.tags,
+            chapters: split_chapters(body),
+            warnings: Vec::new(),
+        })
+    }
+}
@@ -0,0 +1,135 @@
+mod db;
+mod epub;
+mod fingerprint;
+mod format;
+mod markdown;
+mod model;
+mod plaintext;
+
+pub use db::BookDb;
+pub use format::Format;
+pub use model::{Book, Chapter, DuplicateCluster};
+
+/// Open the catalog database, creating it if needed. Called once from
+/// `setup` and then managed as Tauri state.
+pub fn open_db(app_handle: &tauri::AppHandle) -> rusqlite::Result<rusqlite::Connection> {
+    db::open(app_handle)
+}
+
+use std::path::{Path, PathBuf};
+
+use tauri::State;
+
+use format::Parser as _;
+
+/// Outcome of ingesting a single source file: either a newly inserted book,
+/// or the id of the book that already occupied this content.
+pub enum IngestOutcome {
+    Added(Book),
+    AlreadyPresent(i64),
+}
+
+/// Parse a source file (dispatching on its extension to the right
+/// `Parser`) and persist it as a new `Book` (with its `Chapter`s) unless a
+/// book with the same content fingerprint already exists.
+pub fn ingest_file(conn: &rusqlite::Connection, path: &Path) -> Result<IngestOutcome, String> {
+    let path_str = path.to_string_lossy().to_string();
+
+    let content_fingerprint = fingerprint::fingerprint_file(path).map_err(|e| e.to_string())?;
+    if let Some(existing) =
+        db::find_by_fingerprint(conn, &content_fingerprint).map_err(|e| e.to_string())?
+    {
+        return Ok(IngestOutcome::AlreadyPresent(existing.id));
+    }
+
+    let format = Format::from_path(path)
+        .ok_or_else(|| format!("unsupported book format: {}", path_str))?;
+    let parsed = format::parser_for(format).parse(path)?;
+    for warning in &parsed.warnings {
+        log::warn!("{}", warning);
+    }
+
+    let book = db::insert_book(
+        conn,
+        &path_str,
+        &parsed.title,
+        parsed.author.as_deref(),
+        parsed.language.as_deref(),
+        &parsed.tags,
+        &content_fingerprint,
+    )
+    .map_err(|e| e.to_string())?;
+
+    db::insert_chapters(conn, book.id, &parsed.chapters).map_err(|e| e.to_string())?;
+
+    Ok(IngestOutcome::Added(book))
+}
+
+/// Ingest a single book file (markdown, EPUB, or plain text) at `path` into
+/// the catalog.
+#[tauri::command]
+pub async fn add_book(db: State<'_, BookDb>, path: String) -> Result<Book, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    match ingest_file(&conn, Path::new(&path))? {
+        IngestOutcome::Added(book) => Ok(book),
+        IngestOutcome::AlreadyPresent(id) => db::find_by_id(&conn, id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("book {} vanished after lookup", id)),
+    }
+}
+
+/// Group books that share a content fingerprint so the UI can surface
+/// duplicate imports.
+#[tauri::command]
+pub async fn find_duplicate_books(db: State<'_, BookDb>) -> Result<Vec<DuplicateCluster>, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    db::find_duplicate_books(&conn).map_err(|e| e.to_string())
+}
+
+/// Word count for a source file, used by the scan/browse commands so their
+/// `BookEntry` metadata reflects the same parsing ingestion will do rather
+/// than a naive byte-level estimate.
+pub fn word_count(path: &Path) -> Result<usize, String> {
+    let format =
+        Format::from_path(path).ok_or_else(|| format!("unsupported book format: {:?}", path))?;
+    let parsed = format::parser_for(format).parse(path)?;
+    Ok(parsed
+        .chapters
+        .iter()
+        .map(|chapter| chapter.content.split_whitespace().count())
+        .sum())
+}
+
+/// Look up a book by id, for resolvers (like the `book://` protocol) that
+/// are handed an id rather than a path.
+pub fn find_by_id(conn: &rusqlite::Connection, id: i64) -> rusqlite::Result<Option<Book>> {
+    db::find_by_id(conn, id)
+}
+
+/// The directory a book's assets (images, etc.) are resolved relative to.
+pub fn base_dir(book: &Book) -> PathBuf {
+    Path::new(&book.path)
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// List every book currently in the catalog.
+#[tauri::command]
+pub async fn list_books(db: State<'_, BookDb>) -> Result<Vec<Book>, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    db::list_books(&conn).map_err(|e| e.to_string())
+}
+
+/// Fetch a single chapter of a book by its zero-based index.
+#[tauri::command]
+pub async fn get_chapter(
+    db: State<'_, BookDb>,
+    book_id: i64,
+    index: i64,
+) -> Result<Chapter, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    db::get_chapter(&conn, book_id, index)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("no chapter {} for book {}", index, book_id))
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// A book ingested into the catalog, with metadata pulled from markdown
+/// frontmatter or EPUB package metadata where available.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Book {
+    pub id: i64,
+    pub path: String,
+    pub title: String,
+    pub author: Option<String>,
+    pub language: Option<String>,
+    pub tags: Vec<String>,
+    pub fingerprint: String,
+}
+
+/// One chapter of a book, as split out of the source by its format's parser.
+/// `idx` is the chapter's position within the book, starting at 0.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Chapter {
+    pub id: i64,
+    pub book_id: i64,
+    pub idx: i64,
+    pub heading: String,
+    pub content: String,
+}
+
+/// A group of books that share a content fingerprint, i.e. the same book
+/// imported from more than one location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCluster {
+    pub fingerprint: String,
+    pub books: Vec<Book>,
+}
@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use super::format::{ParsedBook, ParsedChapter, Parser};
+
+/// Split plain text into chapters on runs of blank lines or form-feed
+/// characters, the two conventions plain-text ebooks use to mark chapter
+/// breaks.
+fn split_chapters(body: &str) -> Vec<ParsedChapter> {
+    let mut chapters = Vec::new();
+    let mut current = String::new();
+    let mut blank_run = 0;
+
+    for line in body.split('\n') {
+        if line.contains('\x0c') {
+            for (i, piece) in line.split('\x0c').enumerate() {
+                if i > 0 {
+                    flush_chapter(&mut chapters, &mut current);
+                }
+                if !piece.trim().is_empty() {
+                    current.push_str(piece);
+                    current.push('\n');
+                }
+            }
+            blank_run = 0;
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run >= 2 {
+                flush_chapter(&mut chapters, &mut current);
+            }
+            continue;
+        }
+
+        blank_run = 0;
+        current.push_str(line);
+        current.push('\n');
+    }
+
+    flush_chapter(&mut chapters, &mut current);
+    chapters
+}
+
+fn flush_chapter(chapters: &mut Vec<ParsedChapter>, current: &mut String) {
+    let trimmed = current.trim();
+    if !trimmed.is_empty() {
+        chapters.push(ParsedChapter {
+            heading: format!("Chapter {}", chapters.len() + 1),
+            content: trimmed.to_string(),
+        });
+    }
+    current.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_blank_lines_split_chapters() {
+        let chapters = split_chapters("first chapter\n\n\nsecond chapter\n");
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].content, "first chapter");
+        assert_eq!(chapters[0].heading, "Chapter 1");
+        assert_eq!(chapters[1].content, "second chapter");
+        assert_eq!(chapters[1].heading, "Chapter 2");
+    }
+
+    #[test]
+    fn a_single_blank_line_does_not_split_chapters() {
+        let chapters = split_chapters("line one\n\nline two\n");
+        assert_eq!(chapters.len(), 1);
+        assert_eq!(chapters[0].content, "line one\nline two");
+    }
+
+    #[test]
+    fn a_form_feed_splits_chapters() {
+        let chapters = split_chapters("first chapter\n\x0c\nsecond chapter\n");
+        assert_eq!(chapters.len(), 2);
+        assert_eq!(chapters[0].content, "first chapter");
+        assert_eq!(chapters[1].content, "second chapter");
+    }
+
+    #[test]
+    fn blank_body_produces_no_chapters() {
+        assert!(split_chapters("").is_empty());
+        assert!(split_chapters("\n\n\n\n").is_empty());
+    }
+}
+
+/// Parses plain-text files, falling back to the filename as the title since
+/// `.txt` carries no metadata of its own.
+pub struct PlainTextParser;
+
+impl Parser for PlainTextParser {
+    fn parse(&self, path: &Path) -> Result<ParsedBook, String> {
+        let body = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+
+        let title = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string_lossy().to_string());
+
+        Ok(ParsedBook {
+            title,
+            author: None,
+            language: None,
+            tags: Vec::new(),
+            chapters: split_chapters(&body),
+            warnings: Vec::new(),
+        })
+    }
+}
@@ -1,57 +1,42 @@
-use std::path::PathBuf;
-use serde::{Deserialize, Serialize};
+mod books;
+mod protocol;
+mod scanner;
 
-#[derive(Debug, Serialize, Deserialize)]
-struct ScanResult {
-    total: usize,
-    added: usize,
-    skipped: usize,
-    errors: Vec<String>,
-}
-
-/// Scan a directory for markdown files
-#[tauri::command]
-async fn scan_directory_for_books(dir_path: String) -> Result<ScanResult, String> {
-    let path = PathBuf::from(&dir_path);
-    
-    if !path.exists() {
-        return Err(format!("Directory does not exist: {}", dir_path));
-    }
-    
-    if !path.is_dir() {
-        return Err(format!("Path is not a directory: {}", dir_path));
-    }
-
-    let mut result = ScanResult {
-        total: 0,
-        added: 0,
-        skipped: 0,
-        errors: Vec::new(),
-    };
+use books::BookDb;
+use scanner::{BookEntry, ScanResult};
+use tauri::Manager;
 
-    // Read directory entries
-    let entries = match std::fs::read_dir(&path) {
-        Ok(entries) => entries,
-        Err(e) => return Err(format!("Failed to read directory: {}", e)),
-    };
+/// Default recursion depth for directory scans when the frontend doesn't
+/// specify one, deep enough for typical author/series folder nesting.
+const DEFAULT_MAX_DEPTH: usize = 8;
 
-    for entry in entries {
-        if let Ok(entry) = entry {
-            let file_path = entry.path();
-            
-            // Check if it's a markdown file
-            if let Some(ext) = file_path.extension() {
-                if ext == "md" || ext == "markdown" {
-                    result.total += 1;
-                    // Note: You'll need to call the frontend's addBook function
-                    // or implement the book processing logic here
-                    // For now, we just count the files
-                }
-            }
-        }
-    }
+/// Scan a directory (and its subdirectories) for markdown, EPUB, and
+/// plain-text books, ingesting each one into the catalog and emitting a
+/// `scan-progress` event as it is found.
+#[tauri::command]
+async fn scan_directory_for_books(
+    app_handle: tauri::AppHandle,
+    db: tauri::State<'_, BookDb>,
+    dir_path: String,
+    max_depth: Option<usize>,
+) -> Result<ScanResult, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    scanner::scan_directory(
+        &app_handle,
+        &conn,
+        &dir_path,
+        max_depth.unwrap_or(DEFAULT_MAX_DEPTH),
+    )
+}
 
-    Ok(result)
+/// Walk a directory and return per-file metadata for every markdown, EPUB,
+/// or plain-text book found, without importing anything into the catalog.
+#[tauri::command]
+async fn list_directory_books(
+    dir_path: String,
+    max_depth: Option<usize>,
+) -> Result<Vec<BookEntry>, String> {
+    scanner::list_directory_books(&dir_path, max_depth.unwrap_or(DEFAULT_MAX_DEPTH))
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -59,6 +44,7 @@ pub fn run() {
   tauri::Builder::default()
     .plugin(tauri_plugin_fs::init())
     .plugin(tauri_plugin_dialog::init())
+    .register_uri_scheme_protocol(protocol::SCHEME, protocol::handle)
     .setup(|app| {
       if cfg!(debug_assertions) {
         app.handle().plugin(
@@ -67,9 +53,18 @@ pub fn run() {
             .build(),
         )?;
       }
+      let conn = books::open_db(&app.handle())?;
+      app.manage(BookDb(std::sync::Mutex::new(conn)));
       Ok(())
     })
-    .invoke_handler(tauri::generate_handler![scan_directory_for_books])
+    .invoke_handler(tauri::generate_handler![
+      scan_directory_for_books,
+      list_directory_books,
+      books::add_book,
+      books::list_books,
+      books::get_chapter,
+      books::find_duplicate_books
+    ])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
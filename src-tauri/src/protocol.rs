@@ -0,0 +1,117 @@
+use std::path::{Path, PathBuf};
+
+use tauri::http::{Request, Response, StatusCode};
+use tauri::{AppHandle, Manager, UriSchemeContext, Wry};
+
+use crate::books::{self, BookDb};
+
+/// Name of the custom scheme the reader view loads figures and raw chapter
+/// markdown from: `book://<book_id>/<relative_path>`.
+pub const SCHEME: &str = "book";
+
+/// Handle a `book://` request by resolving `<relative_path>` against the
+/// referenced book's directory on disk. Rejects any path that escapes that
+/// directory once canonicalized, so a crafted `../` can't read arbitrary
+/// files through the webview.
+pub fn handle(ctx: UriSchemeContext<'_, Wry>, request: Request<Vec<u8>>) -> Response<Vec<u8>> {
+    match respond(ctx.app_handle(), &request) {
+        Ok(response) => response,
+        Err(status) => Response::builder()
+            .status(status)
+            .body(Vec::new())
+            .expect("error response is well-formed"),
+    }
+}
+
+fn respond(app_handle: &AppHandle, request: &Request<Vec<u8>>) -> Result<Response<Vec<u8>>, StatusCode> {
+    let uri = request.uri();
+
+    let book_id: i64 = uri
+        .host()
+        .ok_or(StatusCode::BAD_REQUEST)?
+        .parse()
+        .map_err(|_| StatusCode::BAD_REQUEST)?;
+    let relative_path = uri.path().trim_start_matches('/');
+    if relative_path.is_empty() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    let db = app_handle.state::<BookDb>();
+    let conn = db.0.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let book = books::find_by_id(&conn, book_id)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    drop(conn);
+
+    let canonical_requested = resolve_within_base(&books::base_dir(&book), relative_path)?;
+
+    let bytes = std::fs::read(&canonical_requested).map_err(|_| StatusCode::NOT_FOUND)?;
+    let mime = guess_mime(&canonical_requested);
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", mime)
+        .body(bytes)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+fn guess_mime(path: &Path) -> String {
+    mime_guess::from_path(path)
+        .first_or_octet_stream()
+        .essence_str()
+        .to_string()
+}
+
+/// Resolve `relative_path` against `base_dir` and reject it (as 404/403)
+/// if it escapes `base_dir` once both are canonicalized, so a crafted
+/// `../` can't read arbitrary files on disk through the webview.
+fn resolve_within_base(base_dir: &Path, relative_path: &str) -> Result<PathBuf, StatusCode> {
+    let requested = base_dir.join(relative_path);
+
+    let canonical_base = base_dir.canonicalize().map_err(|_| StatusCode::NOT_FOUND)?;
+    let canonical_requested = requested.canonicalize().map_err(|_| StatusCode::NOT_FOUND)?;
+    if !canonical_requested.starts_with(&canonical_base) {
+        return Err(StatusCode::FORBIDDEN);
+    }
+
+    Ok(canonical_requested)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("read-bridge-protocol-test-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn resolves_a_path_inside_the_base_dir() {
+        let base = temp_dir("ok");
+        std::fs::write(base.join("figure.png"), b"fake png").unwrap();
+
+        let resolved = resolve_within_base(&base, "figure.png").unwrap();
+        assert_eq!(resolved, base.canonicalize().unwrap().join("figure.png"));
+    }
+
+    #[test]
+    fn rejects_a_relative_path_that_escapes_the_base_dir() {
+        let base = temp_dir("escape-base");
+        std::fs::create_dir_all(&base).unwrap();
+        let secret_dir = base.parent().unwrap().join("read-bridge-protocol-test-secret");
+        let _ = std::fs::remove_dir_all(&secret_dir);
+        std::fs::create_dir_all(&secret_dir).unwrap();
+        std::fs::write(secret_dir.join("secret.txt"), b"top secret").unwrap();
+
+        let escape = format!(
+            "../{}/secret.txt",
+            secret_dir.file_name().unwrap().to_string_lossy()
+        );
+        let result = resolve_within_base(&base, &escape);
+
+        assert_eq!(result, Err(StatusCode::FORBIDDEN));
+    }
+}
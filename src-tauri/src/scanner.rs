@@ -0,0 +1,269 @@
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+use walkdir::{DirEntry, WalkDir};
+
+use crate::books::{self, Format, IngestOutcome};
+
+/// A single file-level failure encountered during a scan, kept alongside the
+/// offending path so one unreadable entry doesn't abort the whole walk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScanError {
+    pub path: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScanResult {
+    pub total: usize,
+    pub added: usize,
+    pub skipped: usize,
+    pub errors: Vec<ScanError>,
+}
+
+impl ScanResult {
+    fn new() -> Self {
+        ScanResult {
+            total: 0,
+            added: 0,
+            skipped: 0,
+            errors: Vec::new(),
+        }
+    }
+}
+
+/// Progress payload emitted on the `scan-progress` event as each book file
+/// is discovered, so the frontend can render an incremental list instead of
+/// waiting for the whole tree to be walked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ScanProgress<'a> {
+    path: &'a str,
+    total_so_far: usize,
+}
+
+/// A book file (markdown, EPUB, or plain text) found while walking a
+/// directory, with the metadata the frontend needs to render a library view
+/// without re-statting every file itself.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookEntry {
+    pub name: String,
+    pub path: String,
+    pub size: u64,
+    pub created: Option<i64>,
+    pub modified: Option<i64>,
+    pub accessed: Option<i64>,
+    pub word_count: usize,
+}
+
+fn is_supported_book(path: &Path) -> bool {
+    Format::from_path(path).is_some()
+}
+
+fn unix_millis(time: std::io::Result<SystemTime>) -> Option<i64> {
+    let duration = time.ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as i64)
+}
+
+/// Build the recursive, non-symlink-following walker shared by every
+/// directory-scanning command.
+fn walk_books(dir_path: &str, max_depth: usize) -> impl Iterator<Item = walkdir::Result<DirEntry>> {
+    WalkDir::new(dir_path)
+        .max_depth(max_depth)
+        .follow_links(false)
+        .into_iter()
+}
+
+/// Extract a `BookEntry` for a supported book file, degrading gracefully
+/// (to `None`) when `created`/`accessed` timestamps aren't available on
+/// this platform or filesystem, rather than failing the whole entry.
+fn extract_entry(entry: &DirEntry) -> Result<BookEntry, String> {
+    let metadata = entry.metadata().map_err(|e| e.to_string())?;
+
+    Ok(BookEntry {
+        name: entry.file_name().to_string_lossy().to_string(),
+        path: entry.path().display().to_string(),
+        size: metadata.len(),
+        created: unix_millis(metadata.created()),
+        modified: unix_millis(metadata.modified()),
+        accessed: unix_millis(metadata.accessed()),
+        word_count: books::word_count(entry.path())?,
+    })
+}
+
+/// Recursively walk `dir_path` up to `max_depth` levels deep, ingesting each
+/// supported book file (markdown, EPUB, plain text) into the catalog and
+/// emitting a `scan-progress` event as it is found. Symlinks are not
+/// followed, which avoids symlink-loop cycles.
+pub fn scan_directory(
+    app_handle: &AppHandle,
+    conn: &Connection,
+    dir_path: &str,
+    max_depth: usize,
+) -> Result<ScanResult, String> {
+    let path = PathBuf::from(dir_path);
+
+    if !path.exists() {
+        return Err(format!("Directory does not exist: {}", dir_path));
+    }
+
+    if !path.is_dir() {
+        return Err(format!("Path is not a directory: {}", dir_path));
+    }
+
+    let mut result = ScanResult::new();
+
+    for entry in walk_books(dir_path, max_depth) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(err) => {
+                let offending = err
+                    .path()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| dir_path.to_string());
+                result.errors.push(ScanError {
+                    path: offending,
+                    message: err.to_string(),
+                });
+                continue;
+            }
+        };
+
+        if !entry.file_type().is_file() || !is_supported_book(entry.path()) {
+            continue;
+        }
+
+        result.total += 1;
+
+        let _ = app_handle.emit(
+            "scan-progress",
+            ScanProgress {
+                path: &entry.path().display().to_string(),
+                total_so_far: result.total,
+            },
+        );
+
+        match books::ingest_file(conn, entry.path()) {
+            Ok(IngestOutcome::Added(_)) => result.added += 1,
+            Ok(IngestOutcome::AlreadyPresent(_)) => result.skipped += 1,
+            Err(message) => result.errors.push(ScanError {
+                path: entry.path().display().to_string(),
+                message,
+            }),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "read-bridge-scanner-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn is_supported_book_matches_known_extensions_case_insensitively() {
+        assert!(is_supported_book(Path::new("book.md")));
+        assert!(is_supported_book(Path::new("book.MARKDOWN")));
+        assert!(is_supported_book(Path::new("book.Epub")));
+        assert!(is_supported_book(Path::new("book.txt")));
+        assert!(!is_supported_book(Path::new("book.pdf")));
+        assert!(!is_supported_book(Path::new("book")));
+    }
+
+    #[test]
+    fn walk_books_does_not_follow_symlinks() {
+        let dir = temp_dir("no-symlink-loop");
+        std::fs::write(dir.join("a.md"), b"# A").unwrap();
+        let sub = dir.join("sub");
+        std::fs::create_dir_all(&sub).unwrap();
+        std::fs::write(sub.join("b.md"), b"# B").unwrap();
+
+        #[cfg(unix)]
+        {
+            let _ = std::os::unix::fs::symlink(&dir, sub.join("loop"));
+        }
+
+        let entries: Vec<_> = walk_books(dir.to_str().unwrap(), 8)
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .collect();
+
+        assert_eq!(entries.len(), 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn unix_millis_degrades_to_none_instead_of_erroring() {
+        let unavailable = std::io::Error::new(std::io::ErrorKind::Unsupported, "not available");
+        assert_eq!(unix_millis(Err(unavailable)), None);
+        assert!(unix_millis(Ok(std::time::SystemTime::now())).is_some());
+    }
+
+    #[test]
+    fn extract_entry_reads_metadata_and_word_count_for_a_supported_file() {
+        let dir = temp_dir("extract-entry");
+        std::fs::write(dir.join("book.md"), b"# Title\n\nhello world\n").unwrap();
+
+        let entry = walk_books(dir.to_str().unwrap(), 8)
+            .filter_map(|e| e.ok())
+            .find(|e| e.file_type().is_file())
+            .unwrap();
+
+        let book_entry = extract_entry(&entry).unwrap();
+        assert_eq!(book_entry.name, "book.md");
+        assert_eq!(book_entry.word_count, 2);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
+
+/// Walk `dir_path` and return a `BookEntry` for every supported book file
+/// found, without touching the catalog database. Used to power a library
+/// browse view that doesn't require importing first.
+pub fn list_directory_books(dir_path: &str, max_depth: usize) -> Result<Vec<BookEntry>, String> {
+    let path = PathBuf::from(dir_path);
+
+    if !path.exists() {
+        return Err(format!("Directory does not exist: {}", dir_path));
+    }
+
+    if !path.is_dir() {
+        return Err(format!("Path is not a directory: {}", dir_path));
+    }
+
+    let mut entries = Vec::new();
+
+    for entry in walk_books(dir_path, max_depth) {
+        let entry = match entry {
+            Ok(entry) => entry,
+            // A single unreadable subdirectory shouldn't fail the whole
+            // browse, same as scan_directory; there's no per-entry errors
+            // field here to record it in, so just skip it.
+            Err(_) => continue,
+        };
+
+        if !entry.file_type().is_file() || !is_supported_book(entry.path()) {
+            continue;
+        }
+
+        if let Ok(book_entry) = extract_entry(&entry) {
+            entries.push(book_entry);
+        }
+    }
+
+    Ok(entries)
+}